@@ -13,6 +13,9 @@ pub enum MeridianError {
     
     #[msg("Invalid Wormhole program ID")]
     InvalidWormholeProgram,
+
+    #[msg("Invalid Squads program ID")]
+    InvalidSquadsProgram,
     
     #[msg("Failed to send Wormhole message")]
     FailedToSendMessage,
@@ -37,4 +40,73 @@ pub enum MeridianError {
     
     #[msg("Gas limit too high")]
     GasLimitTooHigh,
+
+    #[msg("Malformed VAA")]
+    InvalidVaaFormat,
+
+    #[msg("Guardian set does not match the VAA's guardian_set_index")]
+    GuardianSetMismatch,
+
+    #[msg("A guardian signature did not recover to a guardian set entry")]
+    InvalidGuardianSignature,
+
+    #[msg("VAA signatures did not reach guardian quorum")]
+    QuorumNotMet,
+
+    #[msg("VAA emitter does not match the expected emitter")]
+    EmitterMismatch,
+
+    #[msg("VAA sequence does not match the expected sequence")]
+    SequenceMismatch,
+
+    #[msg("This message has already been processed")]
+    DuplicateMessage,
+
+    #[msg("Processed digest account does not match the derived PDA")]
+    InvalidDigestAccount,
+
+    #[msg("Invalid Circle Integration program ID")]
+    InvalidCircleIntegrationProgram,
+
+    #[msg("Invalid Circle Integration custodian")]
+    InvalidCircleCustodian,
+
+    #[msg("Source token account does not match the proposal")]
+    SourceTokenAccountMismatch,
+
+    #[msg("Proposal kind does not match this instruction")]
+    ProposalKindMismatch,
+
+    #[msg("Unrecognized governance action")]
+    UnknownGovernanceAction,
+
+    #[msg("Governance VAA targets a different module")]
+    GovernanceModuleMismatch,
+
+    #[msg("Governance VAA targets a different chain")]
+    GovernanceChainMismatch,
+
+    #[msg("Governance VAA sequence was already applied")]
+    GovernanceSequenceReplayed,
+
+    #[msg("This governance action requires the program upgrade accounts")]
+    MissingUpgradeAccounts,
+
+    #[msg("Upgrade buffer does not match the governance VAA")]
+    UpgradeBufferMismatch,
+
+    #[msg("Guardian set account is not owned by the configured Wormhole program, or is not the canonical PDA for its index")]
+    InvalidGuardianSetAccount,
+
+    #[msg("Source token account is not a valid SPL token account")]
+    InvalidTokenAccount,
+
+    #[msg("Source token account is not owned or delegated to Meridian's token authority")]
+    SourceTokenAccountUnauthorized,
+
+    #[msg("VAA was not emitted by a registered Meridian emitter")]
+    UnregisteredEmitter,
+
+    #[msg("Proposal must be executed before it can be acknowledged")]
+    ProposalNotExecuted,
 }
\ No newline at end of file
@@ -1,10 +1,11 @@
 use crate::errors::MeridianError;
 use crate::state::*;
 use crate::utils::{
-    create_transaction_payload, is_proposal_approved, post_wormhole_message,
-    CONSISTENCY_LEVEL_FINALIZED,
+    create_transaction_payload, is_proposal_approved, open_processed_digest,
+    post_wormhole_message, CONSISTENCY_LEVEL_FINALIZED,
 };
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
 
 #[derive(Accounts)]
 pub struct ExecuteProposal<'info> {
@@ -26,7 +27,8 @@ pub struct ExecuteProposal<'info> {
             &proposal.transaction_index.to_le_bytes(),
         ],
         bump = proposal.bump,
-        constraint = proposal.status == ProposalStatus::Pending @ MeridianError::ProposalNotPending
+        constraint = proposal.status == ProposalStatus::Pending @ MeridianError::ProposalNotPending,
+        constraint = proposal.kind == ProposalKind::Transaction @ MeridianError::ProposalKindMismatch
     )]
     pub proposal: Account<'info, CrossChainProposal>,
 
@@ -40,6 +42,12 @@ pub struct ExecuteProposal<'info> {
     /// CHECK: Linked Squads proposal
     pub squads_proposal: UncheckedAccount<'info>,
 
+    /// CHECK: Squads program
+    #[account(
+        constraint = config.squads_program == squads_program.key() @ MeridianError::InvalidSquadsProgram
+    )]
+    pub squads_program: UncheckedAccount<'info>,
+
     /// CHECK: Wormhole program - don't change this!
     #[account(
         constraint = config.wormhole_program == wormhole_program.key() @ MeridianError::InvalidWormholeProgram
@@ -75,6 +83,12 @@ pub struct ExecuteProposal<'info> {
     )]
     pub emitter: UncheckedAccount<'info>,
 
+    /// CHECK: Replay-protection PDA for this execution's payload digest;
+    /// created inside the handler once the digest is known, so its address
+    /// is verified there rather than through a `seeds` constraint here
+    #[account(mut)]
+    pub processed_digest: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
     pub rent: Sysvar<'info, Rent>,
@@ -84,11 +98,12 @@ pub struct ExecuteProposal<'info> {
 // This is where the cross-chain magic happens!
 pub fn handler(ctx: Context<ExecuteProposal>) -> Result<()> {
     // Verify the proposal is approved by the Squads multisig
-    // This would typically be a CPI to Squads to check the proposal status
     let is_approved = is_proposal_approved(
-        &ctx.accounts.wormhole_program, // Will actually be squads_program in real implementation
+        &ctx.accounts.squads_program,
         &ctx.accounts.multisig,
         &ctx.accounts.squads_proposal,
+        ctx.accounts.proposal.multisig,
+        ctx.accounts.proposal.transaction_index,
     )?;
 
     if !is_approved {
@@ -111,8 +126,32 @@ pub fn handler(ctx: Context<ExecuteProposal>) -> Result<()> {
         proposal.gas_limit,
         config.sequence,
         clock.unix_timestamp,
+    )?;
+
+    // Guard against replaying this exact payload: derive the digest PDA and
+    // create it now, so a second execution producing the same payload fails
+    // at account creation instead of re-sending the message.
+    let digest = keccak::hash(&payload).0;
+    let (expected_digest_pda, digest_bump) =
+        Pubkey::find_program_address(&[b"digest", &digest], ctx.program_id);
+    require_keys_eq!(
+        expected_digest_pda,
+        ctx.accounts.processed_digest.key(),
+        MeridianError::InvalidDigestAccount
     );
 
+    let digest_seeds: &[&[u8]] = &[b"digest", &digest, &[digest_bump]];
+    open_processed_digest(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.processed_digest.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        digest,
+        config.sequence,
+        clock.unix_timestamp,
+        digest_bump,
+        digest_seeds,
+    )?;
+
     // Prepare seeds for the emitter PDA
     let emitter_seeds: &[&[u8]] = &[b"emitter", &[config.emitter_bump]];
 
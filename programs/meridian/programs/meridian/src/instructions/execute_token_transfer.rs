@@ -0,0 +1,249 @@
+use crate::errors::MeridianError;
+use crate::state::*;
+use crate::utils::{
+    burn_and_publish_token_transfer, create_token_transfer_payload, is_proposal_approved,
+    open_processed_digest, post_wormhole_message, CONSISTENCY_LEVEL_FINALIZED,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+#[derive(Accounts)]
+pub struct ExecuteTokenTransfer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, MeridianConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            proposal.multisig.as_ref(),
+            &proposal.transaction_index.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+        constraint = proposal.status == ProposalStatus::Pending @ MeridianError::ProposalNotPending,
+        constraint = proposal.kind == ProposalKind::TokenTransfer @ MeridianError::ProposalKindMismatch
+    )]
+    pub proposal: Account<'info, CrossChainProposal>,
+
+    /// CHECK: Squads multisig that owns this proposal
+    #[account(
+        constraint = config.authorized_multisig == multisig.key() @ MeridianError::UnauthorizedMultisig,
+        constraint = proposal.multisig == multisig.key()
+    )]
+    pub multisig: UncheckedAccount<'info>,
+
+    /// CHECK: Linked Squads proposal
+    pub squads_proposal: UncheckedAccount<'info>,
+
+    /// CHECK: Squads program
+    #[account(
+        constraint = config.squads_program == squads_program.key() @ MeridianError::InvalidSquadsProgram
+    )]
+    pub squads_program: UncheckedAccount<'info>,
+
+    /// CHECK: The Wormhole Circle Integration program
+    #[account(
+        constraint = config.circle_integration_program == circle_integration_program.key()
+            @ MeridianError::InvalidCircleIntegrationProgram
+    )]
+    pub circle_integration_program: UncheckedAccount<'info>,
+
+    /// CHECK: Circle Integration's custodian PDA
+    #[account(
+        constraint = config.circle_custodian == circle_custodian.key()
+            @ MeridianError::InvalidCircleCustodian
+    )]
+    pub circle_custodian: UncheckedAccount<'info>,
+
+    /// CHECK: USDC token account debited for this transfer
+    #[account(
+        mut,
+        constraint = proposal.source_token_account == source_token_account.key()
+            @ MeridianError::SourceTokenAccountMismatch
+    )]
+    pub source_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Meridian's PDA that owns or is delegated over
+    /// `source_token_account`; signs the CCTP burn CPI
+    #[account(
+        seeds = [b"token_authority"],
+        bump = config.token_authority_bump,
+        constraint = config.token_authority == token_authority.key()
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Will be created by Circle Integration during the CPI
+    #[account(mut)]
+    pub circle_message: UncheckedAccount<'info>,
+
+    /// CHECK: Wormhole program - don't change this!
+    #[account(
+        constraint = config.wormhole_program == wormhole_program.key() @ MeridianError::InvalidWormholeProgram
+    )]
+    pub wormhole_program: UncheckedAccount<'info>,
+
+    /// CHECK: Bridge PDA from Wormhole
+    #[account(
+        constraint = config.wormhole_bridge == wormhole_bridge.key()
+    )]
+    pub wormhole_bridge: UncheckedAccount<'info>,
+
+    /// CHECK: Will be created during execution, for Meridian's own
+    /// governance message (distinct from Circle Integration's own message)
+    #[account(mut)]
+    pub wormhole_message: UncheckedAccount<'info>,
+
+    /// CHECK: Tracks message sequence
+    #[account(mut)]
+    pub wormhole_sequence: UncheckedAccount<'info>,
+
+    /// CHECK: Where fees go
+    #[account(
+        mut,
+        constraint = config.wormhole_fee_collector == wormhole_fee_collector.key()
+    )]
+    pub wormhole_fee_collector: UncheckedAccount<'info>,
+
+    /// CHECK: Our emitter PDA
+    #[account(
+        seeds = [b"emitter"],
+        bump = config.emitter_bump,
+        constraint = config.emitter == emitter.key()
+    )]
+    pub emitter: UncheckedAccount<'info>,
+
+    /// CHECK: Replay-protection PDA for this execution's governance payload
+    /// digest; created inside the handler once the digest is known
+    #[account(mut)]
+    pub processed_digest: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Main handler for executing a USDC transfer proposal: burns the USDC via
+// CCTP through Circle Integration, then posts our own governance message
+// referencing the CCTP nonce so the EVM side can pair the two.
+pub fn handler(ctx: Context<ExecuteTokenTransfer>) -> Result<()> {
+    // Verify the proposal is approved by the Squads multisig
+    let is_approved = is_proposal_approved(
+        &ctx.accounts.squads_program,
+        &ctx.accounts.multisig,
+        &ctx.accounts.squads_proposal,
+        ctx.accounts.proposal.multisig,
+        ctx.accounts.proposal.transaction_index,
+    )?;
+
+    if !is_approved {
+        return err!(MeridianError::ProposalNotApproved);
+    }
+
+    let config = &mut ctx.accounts.config;
+    let proposal = &mut ctx.accounts.proposal;
+    let clock = Clock::get()?;
+
+    // Increment sequence for this message, and reuse it as the CCTP/Wormhole
+    // nonce so the minted funds and the governance payload can be paired
+    config.sequence += 1;
+    let cctp_nonce = config.sequence as u32;
+
+    let token_authority_seeds: &[&[u8]] =
+        &[b"token_authority", &[config.token_authority_bump]];
+
+    burn_and_publish_token_transfer(
+        ctx.accounts.circle_integration_program.to_account_info(),
+        ctx.accounts.circle_custodian.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.source_token_account.to_account_info(),
+        ctx.accounts.token_authority.to_account_info(),
+        ctx.accounts.circle_message.to_account_info(),
+        ctx.accounts.wormhole_bridge.to_account_info(),
+        ctx.accounts.wormhole_fee_collector.to_account_info(),
+        ctx.accounts.clock.to_account_info(),
+        ctx.accounts.rent.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        proposal.amount,
+        proposal.target_chain,
+        proposal.recipient,
+        cctp_nonce,
+        token_authority_seeds,
+    )?;
+
+    // Create the governance payload for Wormhole
+    let payload = create_token_transfer_payload(
+        proposal.key(),
+        proposal.target_chain,
+        proposal.recipient,
+        proposal.amount,
+        cctp_nonce,
+        config.sequence,
+        clock.unix_timestamp,
+    )?;
+
+    // Guard against replaying this governance payload: derive the digest PDA
+    // and create it now, so a second execution fails at account creation.
+    let digest = keccak::hash(&payload).0;
+    let (expected_digest_pda, digest_bump) =
+        Pubkey::find_program_address(&[b"digest", &digest], ctx.program_id);
+    require_keys_eq!(
+        expected_digest_pda,
+        ctx.accounts.processed_digest.key(),
+        MeridianError::InvalidDigestAccount
+    );
+
+    let digest_seeds: &[&[u8]] = &[b"digest", &digest, &[digest_bump]];
+    open_processed_digest(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.processed_digest.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        digest,
+        config.sequence,
+        clock.unix_timestamp,
+        digest_bump,
+        digest_seeds,
+    )?;
+
+    // Prepare seeds for the emitter PDA
+    let emitter_seeds: &[&[u8]] = &[b"emitter", &[config.emitter_bump]];
+
+    // Post the governance message to Wormhole
+    post_wormhole_message(
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.wormhole_program.to_account_info(),
+        ctx.accounts.wormhole_bridge.to_account_info(),
+        ctx.accounts.wormhole_message.to_account_info(),
+        ctx.accounts.emitter.to_account_info(),
+        ctx.accounts.wormhole_sequence.to_account_info(),
+        ctx.accounts.wormhole_fee_collector.to_account_info(),
+        ctx.accounts.clock.to_account_info(),
+        ctx.accounts.rent.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        0, // nonce
+        payload,
+        CONSISTENCY_LEVEL_FINALIZED,
+        emitter_seeds,
+    )?;
+
+    // Update proposal status
+    proposal.status = ProposalStatus::Executed;
+    proposal.wormhole_sequence = Some(config.sequence);
+    proposal.cctp_nonce = Some(cctp_nonce as u64);
+    proposal.executed_at = Some(clock.unix_timestamp);
+
+    msg!(
+        "Executed token transfer for transaction index: {}",
+        proposal.transaction_index
+    );
+    msg!("CCTP nonce: {}", cctp_nonce);
+    msg!("Wormhole sequence: {}", config.sequence);
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use crate::errors::MeridianError;
+use crate::state::*;
+use crate::utils::{
+    derive_guardian_set_pda, parse_governance_payload, parse_vaa, read_pubkey_body,
+    verify_vaa_quorum, GovernanceAction, GuardianSetData, GOVERNANCE_MODULE,
+    SOLANA_WORMHOLE_CHAIN_ID,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{bpf_loader_upgradeable, program::invoke_signed};
+
+#[derive(Accounts)]
+pub struct ApplyGovernanceVaa<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, MeridianConfig>,
+
+    /// CHECK: Holds the raw, posted VAA bytes relayed from the guardians
+    pub vaa: UncheckedAccount<'info>,
+
+    /// CHECK: Wormhole guardian set PDA for the VAA's guardian_set_index.
+    /// Ownership is checked here; the handler re-derives the PDA itself once
+    /// the VAA's guardian_set_index is known, since `seeds` can't reference
+    /// a value that only exists inside the VAA body
+    #[account(
+        constraint = guardian_set.owner == &config.wormhole_program @ MeridianError::InvalidGuardianSetAccount
+    )]
+    pub guardian_set: UncheckedAccount<'info>,
+
+    /// CHECK: This program's own executable account; only read for `UpgradeContract`
+    pub program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This program's ProgramData account; only written for `UpgradeContract`
+    #[account(mut)]
+    pub program_data: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: New program buffer; only consumed for `UpgradeContract`
+    #[account(mut)]
+    pub buffer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Receives the buffer's leftover lamports; only used for `UpgradeContract`
+    #[account(mut)]
+    pub spill: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: PDA that holds upgrade authority over this program; only used
+    /// for `UpgradeContract`, but always derived so its bump is available
+    #[account(seeds = [b"program_authority"], bump)]
+    pub program_authority: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Applies a signed Wormhole governance VAA to Meridian's config. The same
+// guardian set that secures Meridian's cross-chain messages also secures its
+// own configuration, rather than trusting a single authority key.
+pub fn handler(ctx: Context<ApplyGovernanceVaa>) -> Result<()> {
+    let (header, body, body_bytes) = {
+        let vaa_data = ctx.accounts.vaa.try_borrow_data()?;
+        parse_vaa(&vaa_data)?
+    };
+
+    // Guardians sign any message posted through the core bridge, regardless
+    // of which program emitted it - without this, anyone could deploy a
+    // throwaway contract on any Wormhole-connected chain, publish a payload
+    // shaped like a Meridian governance packet, and replay the resulting
+    // guardian-signed VAA here.
+    require_eq!(
+        body.emitter_chain,
+        ctx.accounts.config.governance_emitter_chain,
+        MeridianError::UnregisteredEmitter
+    );
+    require!(
+        body.emitter_address == ctx.accounts.config.governance_emitter_address,
+        MeridianError::UnregisteredEmitter
+    );
+
+    let expected_guardian_set = derive_guardian_set_pda(
+        &ctx.accounts.config.wormhole_program,
+        header.guardian_set_index,
+    );
+    require_keys_eq!(
+        expected_guardian_set,
+        ctx.accounts.guardian_set.key(),
+        MeridianError::InvalidGuardianSetAccount
+    );
+
+    let guardian_set = {
+        let guardian_set_data = ctx.accounts.guardian_set.try_borrow_data()?;
+        GuardianSetData::try_from_slice(&guardian_set_data)
+            .map_err(|_| error!(MeridianError::InvalidVaaFormat))?
+    };
+
+    require_eq!(
+        header.guardian_set_index,
+        guardian_set.index,
+        MeridianError::GuardianSetMismatch
+    );
+
+    verify_vaa_quorum(&header, &body_bytes, &guardian_set)?;
+
+    require!(
+        body.sequence > ctx.accounts.config.last_governance_sequence,
+        MeridianError::GovernanceSequenceReplayed
+    );
+
+    let governance = parse_governance_payload(&body.payload)?;
+
+    require!(
+        governance.module == GOVERNANCE_MODULE,
+        MeridianError::GovernanceModuleMismatch
+    );
+    require_eq!(
+        governance.target_chain,
+        SOLANA_WORMHOLE_CHAIN_ID,
+        MeridianError::GovernanceChainMismatch
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.last_governance_sequence = body.sequence;
+
+    match governance.action {
+        GovernanceAction::UpdateAuthorizedMultisig => {
+            let new_multisig = read_pubkey_body(&governance.body)?;
+            config.authorized_multisig = new_multisig;
+            msg!("Authorized multisig updated to {}", new_multisig);
+        }
+        GovernanceAction::UpdateWormholeProgram => {
+            let new_wormhole_program = read_pubkey_body(&governance.body)?;
+            config.wormhole_program = new_wormhole_program;
+            msg!("Wormhole program updated to {}", new_wormhole_program);
+        }
+        GovernanceAction::UpgradeContract => {
+            let new_buffer = read_pubkey_body(&governance.body)?;
+
+            let program = ctx
+                .accounts
+                .program
+                .as_ref()
+                .ok_or(error!(MeridianError::MissingUpgradeAccounts))?;
+            let program_data = ctx
+                .accounts
+                .program_data
+                .as_ref()
+                .ok_or(error!(MeridianError::MissingUpgradeAccounts))?;
+            let buffer = ctx
+                .accounts
+                .buffer
+                .as_ref()
+                .ok_or(error!(MeridianError::MissingUpgradeAccounts))?;
+            let spill = ctx
+                .accounts
+                .spill
+                .as_ref()
+                .ok_or(error!(MeridianError::MissingUpgradeAccounts))?;
+
+            require_keys_eq!(
+                buffer.key(),
+                new_buffer,
+                MeridianError::UpgradeBufferMismatch
+            );
+
+            let authority_seeds: &[&[u8]] = &[b"program_authority", &[ctx.bumps.program_authority]];
+
+            let upgrade_ix = bpf_loader_upgradeable::upgrade(
+                &program.key(),
+                &buffer.key(),
+                &ctx.accounts.program_authority.key(),
+                &spill.key(),
+            );
+
+            invoke_signed(
+                &upgrade_ix,
+                &[
+                    program_data.to_account_info(),
+                    program.to_account_info(),
+                    buffer.to_account_info(),
+                    spill.to_account_info(),
+                    ctx.accounts.rent.to_account_info(),
+                    ctx.accounts.clock.to_account_info(),
+                    ctx.accounts.program_authority.to_account_info(),
+                ],
+                &[authority_seeds],
+            )?;
+
+            msg!("Program upgraded from buffer {}", new_buffer);
+        }
+    }
+
+    msg!("Applied governance VAA with sequence: {}", body.sequence);
+
+    Ok(())
+}
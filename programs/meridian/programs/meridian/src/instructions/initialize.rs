@@ -19,6 +19,10 @@ pub struct Initialize<'info> {
     /// CHECK: This is the multisig that will be authorized to use Meridian
     pub authorized_multisig: UncheckedAccount<'info>,
 
+    /// The Squads program
+    /// CHECK: Stored in config; the execute instructions check against it
+    pub squads_program: UncheckedAccount<'info>,
+
     /// The Wormhole program
     /// CHECK: Validated in the instruction
     pub wormhole_program: UncheckedAccount<'info>,
@@ -39,28 +43,77 @@ pub struct Initialize<'info> {
     )]
     pub emitter: UncheckedAccount<'info>,
 
+    /// The Wormhole Circle Integration program
+    /// CHECK: Stored in config; execute_token_transfer checks against it
+    pub circle_integration_program: UncheckedAccount<'info>,
+
+    /// The Circle Integration custodian PDA
+    /// CHECK: Will be validated later when used
+    pub circle_custodian: UncheckedAccount<'info>,
+
+    /// The PDA Meridian uses as owner or delegate over token accounts it
+    /// burns USDC from via CCTP
+    /// CHECK: This is a PDA of the Meridian program
+    #[account(
+        seeds = [b"token_authority"],
+        bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<Initialize>) -> Result<()> {
+pub fn handler(
+    ctx: Context<Initialize>,
+    registered_emitter_chain: u16,
+    registered_emitter_address: [u8; 32],
+    governance_emitter_chain: u16,
+    governance_emitter_address: [u8; 32],
+) -> Result<()> {
     let config = &mut ctx.accounts.config;
 
     // Set the configuration values
     config.authority = ctx.accounts.authority.key();
     config.authorized_multisig = ctx.accounts.authorized_multisig.key();
+    config.squads_program = ctx.accounts.squads_program.key();
     config.wormhole_program = ctx.accounts.wormhole_program.key();
     config.wormhole_bridge = ctx.accounts.wormhole_bridge.key();
     config.wormhole_fee_collector = ctx.accounts.wormhole_fee_collector.key();
     config.emitter = ctx.accounts.emitter.key();
     config.emitter_bump = ctx.bumps.emitter;
     config.sequence = 0;
+    config.circle_integration_program = ctx.accounts.circle_integration_program.key();
+    config.circle_custodian = ctx.accounts.circle_custodian.key();
+    config.token_authority = ctx.accounts.token_authority.key();
+    config.token_authority_bump = ctx.bumps.token_authority;
+    config.registered_emitter_chain = registered_emitter_chain;
+    config.registered_emitter_address = registered_emitter_address;
+    config.governance_emitter_chain = governance_emitter_chain;
+    config.governance_emitter_address = governance_emitter_address;
+    config.last_governance_sequence = 0;
     config.bump = ctx.bumps.config;
 
     msg!("Meridian initialized with authority: {}", config.authority);
     msg!("Authorized multisig: {}", config.authorized_multisig);
+    msg!("Squads program: {}", config.squads_program);
     msg!("Wormhole program: {}", config.wormhole_program);
     msg!("Emitter: {}", config.emitter);
+    msg!(
+        "Circle Integration program: {}",
+        config.circle_integration_program
+    );
+    msg!("Token authority: {}", config.token_authority);
+    msg!(
+        "Registered emitter: chain {} address {:?}",
+        config.registered_emitter_chain,
+        config.registered_emitter_address
+    );
+    msg!(
+        "Governance emitter: chain {} address {:?}",
+        config.governance_emitter_chain,
+        config.governance_emitter_address
+    );
 
     Ok(())
 }
@@ -0,0 +1,7 @@
+pub mod execute;
+pub mod execute_token_transfer;
+pub mod governance;
+pub mod initialize;
+pub mod propose;
+pub mod propose_token_transfer;
+pub mod receive_vaa;
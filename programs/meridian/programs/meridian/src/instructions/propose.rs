@@ -64,6 +64,12 @@ pub fn handler(
     proposal.target_address = target_address;
     proposal.call_data = call_data;
     proposal.gas_limit = gas_limit;
+    proposal.kind = ProposalKind::Transaction;
+    proposal.amount = 0;
+    proposal.source_token_account = Pubkey::default();
+    proposal.destination_mint = [0; 32];
+    proposal.recipient = [0; 32];
+    proposal.cctp_nonce = None;
     proposal.status = ProposalStatus::Pending;
     proposal.wormhole_sequence = None;
     proposal.created_at = Clock::get()?.unix_timestamp;
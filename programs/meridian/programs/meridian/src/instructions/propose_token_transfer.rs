@@ -0,0 +1,104 @@
+use crate::errors::MeridianError;
+use crate::state::*;
+use crate::utils::{read_token_account_authority, TOKEN_PROGRAM_ID};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(transaction_index: u64, target_chain: u16, amount: u64, destination_mint: [u8; 32], recipient: [u8; 32])]
+pub struct ProposeTokenTransfer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, MeridianConfig>,
+
+    /// CHECK: Make sure it's our authorized multisig
+    #[account(
+        constraint = config.authorized_multisig == multisig.key() @ MeridianError::UnauthorizedMultisig
+    )]
+    pub multisig: UncheckedAccount<'info>,
+
+    /// CHECK: Squads transaction we're linking to
+    pub transaction: UncheckedAccount<'info>,
+
+    /// CHECK: USDC token account this transfer will burn from; the handler
+    /// checks it's an SPL token account owned or delegated to Meridian's
+    /// `token_authority`, since that's the account that will sign the burn
+    /// CPI in execute_token_transfer
+    pub source_token_account: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = CrossChainProposal::space(0),
+        seeds = [
+            b"proposal",
+            multisig.key().as_ref(),
+            &transaction_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, CrossChainProposal>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Creates a new cross-chain proposal to move USDC to an EVM treasury via
+// CCTP. Doesn't touch any funds yet - execute_token_transfer does that once
+// the multisig approves.
+pub fn handler(
+    ctx: Context<ProposeTokenTransfer>,
+    transaction_index: u64,
+    target_chain: u16,
+    amount: u64,
+    destination_mint: [u8; 32],
+    recipient: [u8; 32],
+) -> Result<()> {
+    require_keys_eq!(
+        *ctx.accounts.source_token_account.owner,
+        TOKEN_PROGRAM_ID,
+        MeridianError::InvalidTokenAccount
+    );
+    let token_account_data = ctx.accounts.source_token_account.try_borrow_data()?;
+    let token_authority = read_token_account_authority(&token_account_data)?;
+    drop(token_account_data);
+
+    let expected_authority = ctx.accounts.config.token_authority;
+    require!(
+        token_authority.owner == expected_authority
+            || token_authority.delegate == Some(expected_authority),
+        MeridianError::SourceTokenAccountUnauthorized
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.multisig = ctx.accounts.multisig.key();
+    proposal.transaction_index = transaction_index;
+    proposal.target_chain = target_chain;
+    proposal.target_address = [0; 32];
+    proposal.call_data = Vec::new();
+    proposal.gas_limit = 0;
+    proposal.kind = ProposalKind::TokenTransfer;
+    proposal.amount = amount;
+    proposal.source_token_account = ctx.accounts.source_token_account.key();
+    proposal.destination_mint = destination_mint;
+    proposal.recipient = recipient;
+    proposal.cctp_nonce = None;
+    proposal.status = ProposalStatus::Pending;
+    proposal.wormhole_sequence = None;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.executed_at = None;
+    proposal.bump = ctx.bumps.proposal;
+
+    msg!(
+        "Created token transfer proposal for transaction index: {}",
+        transaction_index
+    );
+    msg!("Target chain: {}", target_chain);
+    msg!("Amount: {}", amount);
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use crate::errors::MeridianError;
+use crate::state::*;
+use crate::utils::{
+    decode, derive_guardian_set_pda, open_processed_digest, parse_vaa, vaa_digest,
+    verify_vaa_quorum, GuardianSetData,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(emitter_chain: u16, emitter_address: [u8; 32], sequence: u64)]
+pub struct ReceiveVaa<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, MeridianConfig>,
+
+    /// CHECK: Holds the raw, posted VAA bytes relayed from the source chain
+    pub vaa: UncheckedAccount<'info>,
+
+    /// CHECK: Wormhole guardian set PDA for the VAA's guardian_set_index.
+    /// Ownership is checked here; the handler re-derives the PDA itself once
+    /// the VAA's guardian_set_index is known, since `seeds` can't reference
+    /// a value that only exists inside the VAA body
+    #[account(
+        constraint = guardian_set.owner == &config.wormhole_program @ MeridianError::InvalidGuardianSetAccount
+    )]
+    pub guardian_set: UncheckedAccount<'info>,
+
+    /// The proposal this VAA is expected to acknowledge, if the caller knows
+    /// one up front - left unset for VAAs that don't reference a proposal.
+    #[account(mut)]
+    pub proposal: Option<Account<'info, CrossChainProposal>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ReceivedMessage::SIZE,
+        seeds = [
+            b"received",
+            &emitter_chain.to_le_bytes(),
+            emitter_address.as_ref(),
+            &sequence.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub received_message: Account<'info, ReceivedMessage>,
+
+    /// CHECK: Replay-protection PDA for this VAA's digest, shared with the
+    /// outgoing `ProcessedDigest` registry; created inside the handler once
+    /// the digest is known, so its address is verified there rather than
+    /// through a `seeds` constraint here
+    #[account(mut)]
+    pub processed_digest: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Verifies a guardian-signed VAA, decodes its payload as a transaction
+// payload (failing if it doesn't decode), and records it. If the payload
+// references the proposal we were handed, that proposal is moved to
+// Acknowledged.
+pub fn handler(
+    ctx: Context<ReceiveVaa>,
+    emitter_chain: u16,
+    emitter_address: [u8; 32],
+    sequence: u64,
+) -> Result<()> {
+    let (header, body, body_bytes) = {
+        let vaa_data = ctx.accounts.vaa.try_borrow_data()?;
+        parse_vaa(&vaa_data)?
+    };
+
+    require_eq!(
+        body.emitter_chain,
+        emitter_chain,
+        MeridianError::EmitterMismatch
+    );
+    require!(
+        body.emitter_address == emitter_address,
+        MeridianError::EmitterMismatch
+    );
+    require_eq!(body.sequence, sequence, MeridianError::SequenceMismatch);
+
+    // The self-consistency checks above only confirm the caller's
+    // instruction args match the VAA body - anyone can post a
+    // guardian-signed VAA from their own program on any Wormhole-connected
+    // chain. Only trust bodies actually emitted by Meridian's registered
+    // counterpart contract.
+    require_eq!(
+        body.emitter_chain,
+        ctx.accounts.config.registered_emitter_chain,
+        MeridianError::UnregisteredEmitter
+    );
+    require!(
+        body.emitter_address == ctx.accounts.config.registered_emitter_address,
+        MeridianError::UnregisteredEmitter
+    );
+
+    let expected_guardian_set = derive_guardian_set_pda(
+        &ctx.accounts.config.wormhole_program,
+        header.guardian_set_index,
+    );
+    require_keys_eq!(
+        expected_guardian_set,
+        ctx.accounts.guardian_set.key(),
+        MeridianError::InvalidGuardianSetAccount
+    );
+
+    let guardian_set = {
+        let guardian_set_data = ctx.accounts.guardian_set.try_borrow_data()?;
+        GuardianSetData::try_from_slice(&guardian_set_data)
+            .map_err(|_| error!(MeridianError::InvalidVaaFormat))?
+    };
+
+    require_eq!(
+        header.guardian_set_index,
+        guardian_set.index,
+        MeridianError::GuardianSetMismatch
+    );
+
+    verify_vaa_quorum(&header, &body_bytes, &guardian_set)?;
+
+    // Guard against replaying this VAA: derive its digest PDA and create it
+    // now, sharing the same ProcessedDigest registry outgoing payloads use.
+    let digest = vaa_digest(&body_bytes);
+    let (expected_digest_pda, digest_bump) =
+        Pubkey::find_program_address(&[b"digest", &digest], ctx.program_id);
+    require_keys_eq!(
+        expected_digest_pda,
+        ctx.accounts.processed_digest.key(),
+        MeridianError::InvalidDigestAccount
+    );
+
+    let received_at = Clock::get()?.unix_timestamp;
+
+    let digest_seeds: &[&[u8]] = &[b"digest", &digest, &[digest_bump]];
+    open_processed_digest(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.processed_digest.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        digest,
+        body.sequence,
+        received_at,
+        digest_bump,
+        digest_seeds,
+    )?;
+
+    let payload = decode(&body.payload)?;
+
+    let received_message = &mut ctx.accounts.received_message;
+    received_message.emitter_chain = body.emitter_chain;
+    received_message.emitter_address = body.emitter_address;
+    received_message.sequence = body.sequence;
+    received_message.received_at = received_at;
+    received_message.bump = ctx.bumps.received_message;
+    received_message.proposal = None;
+    received_message.status = ReceivedMessageStatus::Unmatched;
+
+    if let Some(proposal) = ctx.accounts.proposal.as_mut() {
+        if proposal.key().to_bytes() == payload.proposal_key {
+            require!(
+                proposal.status == ProposalStatus::Executed,
+                MeridianError::ProposalNotExecuted
+            );
+            proposal.status = ProposalStatus::Acknowledged;
+            received_message.proposal = Some(proposal.key());
+            received_message.status = ReceivedMessageStatus::Matched;
+        }
+    }
+
+    msg!(
+        "Received VAA from chain {} sequence {}",
+        body.emitter_chain,
+        body.sequence
+    );
+    if received_message.status == ReceivedMessageStatus::Matched {
+        msg!("Matched proposal, status set to Acknowledged");
+    }
+
+    Ok(())
+}
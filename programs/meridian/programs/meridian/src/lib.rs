@@ -7,13 +7,21 @@ pub mod utils;
 
 // Re-export all items from instructions so that procedural macros can resolve the generated account structs
 pub use instructions::execute::*;
+pub use instructions::execute_token_transfer::*;
+pub use instructions::governance::*;
 pub use instructions::initialize::*;
 pub use instructions::propose::*;
+pub use instructions::propose_token_transfer::*;
+pub use instructions::receive_vaa::*;
 
 // Import the required types
 use instructions::execute::ExecuteProposal;
+use instructions::execute_token_transfer::ExecuteTokenTransfer;
+use instructions::governance::ApplyGovernanceVaa;
 use instructions::initialize::Initialize;
 use instructions::propose::ProposeTransaction;
+use instructions::propose_token_transfer::ProposeTokenTransfer;
+use instructions::receive_vaa::ReceiveVaa;
 
 declare_id!("G6sHax1H3nXc5gu8YzPmgntbQR5e1CWMqYg1ekZmjDTd");
 
@@ -22,8 +30,20 @@ pub mod meridian {
     use super::*;
 
     /// Initialize the Meridian program with a Squads multisig as authority
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        instructions::initialize::handler(ctx)
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        registered_emitter_chain: u16,
+        registered_emitter_address: [u8; 32],
+        governance_emitter_chain: u16,
+        governance_emitter_address: [u8; 32],
+    ) -> Result<()> {
+        instructions::initialize::handler(
+            ctx,
+            registered_emitter_chain,
+            registered_emitter_address,
+            governance_emitter_chain,
+            governance_emitter_address,
+        )
     }
 
     /// Propose a cross-chain transaction to be executed on an EVM chain
@@ -49,4 +69,43 @@ pub mod meridian {
     pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
         instructions::execute::handler(ctx)
     }
+
+    /// Propose moving USDC to an EVM treasury via Circle's CCTP
+    pub fn propose_token_transfer(
+        ctx: Context<ProposeTokenTransfer>,
+        transaction_index: u64,
+        target_chain: u16,
+        amount: u64,
+        destination_mint: [u8; 32],
+        recipient: [u8; 32],
+    ) -> Result<()> {
+        instructions::propose_token_transfer::handler(
+            ctx,
+            transaction_index,
+            target_chain,
+            amount,
+            destination_mint,
+            recipient,
+        )
+    }
+
+    /// Execute an approved USDC transfer proposal via Circle Integration's CCTP
+    pub fn execute_token_transfer(ctx: Context<ExecuteTokenTransfer>) -> Result<()> {
+        instructions::execute_token_transfer::handler(ctx)
+    }
+
+    /// Verify and record an inbound VAA acknowledging a cross-chain execution
+    pub fn receive_vaa(
+        ctx: Context<ReceiveVaa>,
+        emitter_chain: u16,
+        emitter_address: [u8; 32],
+        sequence: u64,
+    ) -> Result<()> {
+        instructions::receive_vaa::handler(ctx, emitter_chain, emitter_address, sequence)
+    }
+
+    /// Apply a signed Wormhole governance VAA to Meridian's configuration
+    pub fn apply_governance_vaa(ctx: Context<ApplyGovernanceVaa>) -> Result<()> {
+        instructions::governance::handler(ctx)
+    }
 }
@@ -8,7 +8,10 @@ pub struct MeridianConfig {
     
     /// The authorized Squads multisig PDA
     pub authorized_multisig: Pubkey,
-    
+
+    /// The Squads program ID
+    pub squads_program: Pubkey,
+
     /// The Wormhole program ID
     pub wormhole_program: Pubkey,
     
@@ -20,13 +23,44 @@ pub struct MeridianConfig {
     
     /// The PDA that acts as the emitter for Wormhole messages
     pub emitter: Pubkey,
-    
+
     /// Bump seed for the emitter PDA
     pub emitter_bump: u8,
-    
+
     /// Current sequence number for Wormhole messages
     pub sequence: u64,
-    
+
+    /// The Wormhole Circle Integration program ID, used to move USDC via CCTP
+    pub circle_integration_program: Pubkey,
+
+    /// The Circle Integration custodian PDA that holds USDC in transit
+    pub circle_custodian: Pubkey,
+
+    /// The PDA Meridian uses as owner or delegate over token accounts it
+    /// burns USDC from via CCTP
+    pub token_authority: Pubkey,
+
+    /// Bump seed for the token_authority PDA
+    pub token_authority_bump: u8,
+
+    /// Wormhole chain id of Meridian's paired EVM contract - the only
+    /// emitter `receive_vaa` will accept acknowledgements from
+    pub registered_emitter_chain: u16,
+
+    /// Wormhole-format (32-byte) address of that paired EVM contract
+    pub registered_emitter_address: [u8; 32],
+
+    /// Wormhole chain id of the source trusted to emit Meridian governance
+    /// VAAs - the only emitter apply_governance_vaa will accept
+    pub governance_emitter_chain: u16,
+
+    /// Wormhole-format (32-byte) address of that trusted governance emitter
+    pub governance_emitter_address: [u8; 32],
+
+    /// Sequence of the last applied governance VAA, so an older or replayed
+    /// governance VAA can't be re-applied
+    pub last_governance_sequence: u64,
+
     /// Bump seed for the config PDA
     pub bump: u8,
 }
@@ -35,11 +69,21 @@ impl MeridianConfig {
     pub const SIZE: usize = 8 + // discriminator
         32 + // authority
         32 + // authorized_multisig
+        32 + // squads_program
         32 + // wormhole_program
         32 + // wormhole_bridge
         32 + // wormhole_fee_collector
         32 + // emitter
         1 +  // emitter_bump
         8 +  // sequence
+        32 + // circle_integration_program
+        32 + // circle_custodian
+        32 + // token_authority
+        1 +  // token_authority_bump
+        2 +  // registered_emitter_chain
+        32 + // registered_emitter_address
+        2 +  // governance_emitter_chain
+        32 + // governance_emitter_address
+        8 +  // last_governance_sequence
         1;   // bump
 }
\ No newline at end of file
@@ -0,0 +1,9 @@
+pub mod config;
+pub mod processed_digest;
+pub mod proposal;
+pub mod received_message;
+
+pub use config::*;
+pub use processed_digest::*;
+pub use proposal::*;
+pub use received_message::*;
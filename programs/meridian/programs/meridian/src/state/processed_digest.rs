@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Replay-protection record for a single outgoing or inbound cross-chain
+/// message. The PDA's existence is the actual protection - a second attempt
+/// to process the same message derives the same address and is rejected
+/// before any state changes happen. `digest` is kept around as an audit
+/// trail of exactly which payload/VAA this entry guards.
+#[account]
+pub struct ProcessedDigest {
+    /// keccak256 digest of the outgoing payload or consumed VAA body
+    pub digest: [u8; 32],
+
+    /// Wormhole sequence number associated with this digest
+    pub sequence: u64,
+
+    /// Timestamp when this digest was recorded
+    pub recorded_at: i64,
+
+    /// Bump seed for the PDA
+    pub bump: u8,
+}
+
+impl ProcessedDigest {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // digest
+        8 +  // sequence
+        8 +  // recorded_at
+        1;   // bump
+}
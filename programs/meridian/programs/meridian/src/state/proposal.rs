@@ -1,5 +1,15 @@
 use anchor_lang::prelude::*;
 
+/// What kind of cross-chain action a `CrossChainProposal` carries
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ProposalKind {
+    // Generic call_data dispatched to target_address on target_chain
+    Transaction,
+
+    // USDC moved to `recipient` on target_chain via Circle's CCTP
+    TokenTransfer,
+}
+
 /// Status of a cross-chain proposal
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub enum ProposalStatus {
@@ -14,6 +24,9 @@ pub enum ProposalStatus {
 
     // Someone explicitly cancelled it
     Cancelled,
+
+    // Destination chain sent back a verified VAA acknowledging execution
+    Acknowledged,
 }
 
 /// Represents a cross-chain transaction proposal
@@ -37,6 +50,24 @@ pub struct CrossChainProposal {
     /// Gas limit for execution
     pub gas_limit: u64,
 
+    /// Which kind of cross-chain action this proposal carries
+    pub kind: ProposalKind,
+
+    /// USDC amount to move, in its native 6-decimal unit (TokenTransfer only)
+    pub amount: u64,
+
+    /// Token account this transfer's USDC is burned from (TokenTransfer only)
+    pub source_token_account: Pubkey,
+
+    /// USDC mint on the destination chain, 32-byte format (TokenTransfer only)
+    pub destination_mint: [u8; 32],
+
+    /// Recipient on the destination chain, 32-byte format (TokenTransfer only)
+    pub recipient: [u8; 32],
+
+    /// CCTP nonce assigned when the transfer was executed (TokenTransfer only)
+    pub cctp_nonce: Option<u64>,
+
     /// Status of this proposal
     pub status: ProposalStatus,
 
@@ -62,6 +93,12 @@ impl CrossChainProposal {
         32 + // target_address
         4 + call_data_len + // call_data (Vec<u8>)
         8 +  // gas_limit
+        1 +  // kind
+        8 +  // amount
+        32 + // source_token_account
+        32 + // destination_mint
+        32 + // recipient
+        9 +  // cctp_nonce (Option<u64>)
         1 +  // status
         9 +  // wormhole_sequence (Option<u64>)
         8 +  // created_at
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+/// Outcome of processing an inbound VAA
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ReceivedMessageStatus {
+    // Verified, but its payload didn't reference a proposal we were given
+    Unmatched,
+
+    // Verified and matched to a CrossChainProposal, which was updated
+    Matched,
+}
+
+/// Records a guardian-verified, inbound VAA. Existence of this PDA is what
+/// prevents the same VAA from being processed twice.
+#[account]
+pub struct ReceivedMessage {
+    /// Emitter chain the VAA originated from
+    pub emitter_chain: u16,
+
+    /// Emitter address on that chain
+    pub emitter_address: [u8; 32],
+
+    /// Wormhole sequence number from the emitter
+    pub sequence: u64,
+
+    /// The CrossChainProposal this message acknowledged, if any
+    pub proposal: Option<Pubkey>,
+
+    /// Outcome of processing this message
+    pub status: ReceivedMessageStatus,
+
+    /// Timestamp when received
+    pub received_at: i64,
+
+    /// Bump seed for the PDA
+    pub bump: u8,
+}
+
+impl ReceivedMessage {
+    pub const SIZE: usize = 8 + // discriminator
+        2 +  // emitter_chain
+        32 + // emitter_address
+        8 +  // sequence
+        33 + // proposal (Option<Pubkey>)
+        1 +  // status
+        8 +  // received_at
+        1;   // bump
+}